@@ -1,7 +1,75 @@
-use human_id::{custom_id_in_language, Language};
+use human_id::{
+    custom_id_from_template_with_rng, entropy_bits, keyspace_size, languages, Case, Language,
+    WordClass, DEFAULT_TEMPLATE,
+};
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+use std::collections::HashSet;
 use std::str::FromStr;
 use structopt::StructOpt;
 
+/// The number of attempts `--unique` will make, per remaining identifier,
+/// before giving up and reporting the keyspace as too small.
+const UNIQUE_RETRY_BOUND: u32 = 100;
+
+/// The machine-readable output format for a batch of identifiers.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum OutputFormat {
+    /// One identifier per line (the default).
+    Lines,
+    /// A JSON array of strings.
+    Json,
+    /// Comma-separated values, one identifier per line.
+    Csv,
+}
+
+impl FromStr for OutputFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "lines" => Ok(Self::Lines),
+            "json" => Ok(Self::Json),
+            "csv" => Ok(Self::Csv),
+            _ => Err(format!("The string '{}' is not a valid output format", s)),
+        }
+    }
+}
+
+impl OutputFormat {
+    fn print(&self, ids: &[String]) {
+        match self {
+            OutputFormat::Lines => {
+                for id in ids {
+                    println!("{}", id);
+                }
+            }
+            OutputFormat::Json => {
+                let escaped: Vec<String> = ids.iter().map(|id| json_escape(id)).collect();
+                println!("[{}]", escaped.join(","));
+            }
+            OutputFormat::Csv => {
+                for id in ids {
+                    println!("{}", csv_escape(id));
+                }
+            }
+        }
+    }
+}
+
+fn json_escape(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+fn csv_escape(value: &str) -> String {
+    if value.contains(',') || value.contains('"') {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
 #[derive(StructOpt)]
 #[structopt(name = "3wid", about = "Generate 3-word human identifiers.")]
 pub struct CommandLine {
@@ -13,6 +81,11 @@ pub struct CommandLine {
     ///
     count: Option<u16>,
 
+    #[structopt(long)]
+    /// Print the list of supported languages and exit.
+    ///
+    list_languages: bool,
+
     #[structopt(short, long)]
     /// The separator string to use between words in the identifier, the
     /// default is none.
@@ -27,23 +100,107 @@ pub struct CommandLine {
     ///
     /// By default the three words comprising the identifier will have their
     /// first character capitalized. This flag turns off this feature and
-    /// generates all lowercase identifiers.
+    /// generates all lowercase identifiers. Ignored if `--case` is given.
     ///
     no_capitalize: bool,
 
+    #[structopt(long)]
+    /// The case style to apply to the generated words, overriding
+    /// `--no-capitalize`.
+    ///
+    /// One of `lower`, `title` (the default), `upper`, `camel`, `pascal`,
+    /// `snake` or `kebab`. The joined styles (`camel`, `pascal`, `snake`,
+    /// `kebab`) each force their own separator, overriding `--separator`.
+    ///
+    case: Option<String>,
+
     #[structopt(short, long)]
     /// The language to choose words from, the default is 'en'.
     ///
     /// The set of words can be chosen from any language although the library
     /// only has a small set of chosen languages. The string is the ISO
     /// 2-character language code in either all lowercase `"en"` or all
-    /// uppercase `"EN"` characters.
+    /// uppercase `"EN"` characters — see the supported codes at the bottom
+    /// of `--help`, or run `--list-languages` for a machine-readable list.
     ///
     language: Option<String>,
+
+    #[structopt(long)]
+    /// A seed for the random number generator, for reproducible output.
+    ///
+    /// Running with the same seed (and the same count, separator, case,
+    /// language, template or word count, and `--unique`) always produces the same identifier or batch of
+    /// identifiers. Without a seed, identifiers are drawn from the system's
+    /// source of randomness and cannot be reproduced.
+    ///
+    seed: Option<u64>,
+
+    #[structopt(long)]
+    /// Generate identifiers with this many words, each of a random class.
+    ///
+    /// The default shape is one adjective, one noun and one verb; this is a
+    /// shortcut for generating shorter or longer identifiers without
+    /// spelling out an explicit `--template`. Ignored if `--template` is
+    /// given.
+    ///
+    words: Option<u16>,
+
+    #[structopt(long)]
+    /// An explicit, comma-separated template of word classes, e.g.
+    /// `adj,noun,verb` or `adj,adj,noun,verb` for higher entropy.
+    ///
+    /// Each entry is one of `adjective` (or `adj`), `noun` or `verb`.
+    /// Overrides `--words`.
+    ///
+    template: Option<String>,
+
+    #[structopt(long, default_value = "lines")]
+    /// The output format for a batch of identifiers: `lines` (the default),
+    /// `json` or `csv`.
+    ///
+    format: String,
+
+    #[structopt(long)]
+    /// De-duplicate a `--count` batch so every identifier is unique.
+    ///
+    /// Re-draws on collision, up to a bounded number of retries per
+    /// remaining identifier; errors out if the requested count is too
+    /// close to (or larger than) the keyspace to realistically satisfy.
+    ///
+    unique: bool,
+
+    #[structopt(long)]
+    /// Print the bits of entropy for the current configuration and exit,
+    /// instead of generating identifiers.
+    ///
+    /// Entropy depends on `--language` and the word-class template
+    /// (`--template` or `--words`), since those determine the keyspace.
+    ///
+    entropy: bool,
 }
 
 pub fn main() {
-    let cmd_line = CommandLine::from_args();
+    // `--help`'s text is normally fixed at compile time by the `StructOpt`
+    // derive, but the list of supported languages is real data (see
+    // `languages()`), so it's spliced into the generated `App` here instead
+    // of being hand-copied into a doc comment that would drift.
+    let after_help = format!(
+        "Supported languages: {}.",
+        languages()
+            .iter()
+            .map(|language| language.code())
+            .collect::<Vec<_>>()
+            .join(", ")
+    );
+    let app = CommandLine::clap().after_help(after_help.as_str());
+    let cmd_line = CommandLine::from_clap(&app.get_matches());
+
+    if cmd_line.list_languages {
+        for language in languages() {
+            println!("{} ({})", language.code(), language.name());
+        }
+        return;
+    }
 
     let separator = cmd_line
         .separator
@@ -51,7 +208,19 @@ pub fn main() {
         .map(|s| s.to_string())
         .unwrap_or_default();
 
-    let capitalize_words = !cmd_line.no_capitalize;
+    let case = if let Some(case) = cmd_line.case.as_ref() {
+        match Case::from_str(case) {
+            Ok(c) => c,
+            Err(e) => {
+                eprintln!("Error: {}", e);
+                std::process::exit(1);
+            }
+        }
+    } else if cmd_line.no_capitalize {
+        Case::Lower
+    } else {
+        Case::Title
+    };
 
     let language = if let Some(language) = cmd_line.language.as_ref() {
         match Language::from_str(&language) {
@@ -65,10 +234,103 @@ pub fn main() {
         Language::default()
     };
 
-    (0..cmd_line.count.unwrap_or(1)).for_each(|_| {
-        println!(
-            "{}",
-            custom_id_in_language(&separator, capitalize_words, language)
-        )
-    })
+    let mut rng = match cmd_line.seed {
+        Some(seed) => StdRng::seed_from_u64(seed),
+        None => StdRng::from_entropy(),
+    };
+
+    let template: Vec<WordClass> = if let Some(template) = cmd_line.template.as_ref() {
+        match template
+            .split(',')
+            .map(|class| WordClass::from_str(class.trim()))
+            .collect::<Result<Vec<WordClass>, String>>()
+        {
+            Ok(template) => template,
+            Err(e) => {
+                eprintln!("Error: {}", e);
+                std::process::exit(1);
+            }
+        }
+    } else if let Some(words) = cmd_line.words {
+        let classes = [WordClass::Adjective, WordClass::Noun, WordClass::Verb];
+        (0..words)
+            .map(|_| *classes.choose(&mut rng).unwrap())
+            .collect()
+    } else {
+        DEFAULT_TEMPLATE.to_vec()
+    };
+
+    if cmd_line.entropy {
+        println!("{:.2} bits", entropy_bits(&template, language));
+        return;
+    }
+
+    let format = match OutputFormat::from_str(&cmd_line.format) {
+        Ok(format) => format,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            std::process::exit(1);
+        }
+    };
+
+    let count = cmd_line.count.unwrap_or(1);
+
+    let ids = if cmd_line.unique {
+        match unique_ids(&mut rng, &template, &separator, case, language, count) {
+            Ok(ids) => ids,
+            Err(e) => {
+                eprintln!("Error: {}", e);
+                std::process::exit(1);
+            }
+        }
+    } else {
+        (0..count)
+            .map(|_| custom_id_from_template_with_rng(&mut rng, &template, &separator, case, language))
+            .collect()
+    };
+
+    format.print(&ids);
+}
+
+/// Draws `count` unique identifiers, retrying on collision up to
+/// `UNIQUE_RETRY_BOUND` times per remaining identifier.
+fn unique_ids<R: rand::Rng>(
+    rng: &mut R,
+    template: &[WordClass],
+    separator: &str,
+    case: Case,
+    language: Language,
+    count: u16,
+) -> Result<Vec<String>, String> {
+    let keyspace = keyspace_size(template, language);
+    if u64::from(count) > keyspace {
+        return Err(format!(
+            "requested {} unique identifiers but the keyspace only holds {}",
+            count, keyspace
+        ));
+    }
+
+    let mut seen = HashSet::new();
+    let mut ids = Vec::with_capacity(count as usize);
+
+    while ids.len() < count as usize {
+        let mut attempts = 0;
+        loop {
+            let id = custom_id_from_template_with_rng(rng, template, separator, case, language);
+            if seen.insert(id.clone()) {
+                ids.push(id);
+                break;
+            }
+
+            attempts += 1;
+            if attempts >= UNIQUE_RETRY_BOUND {
+                return Err(format!(
+                    "could not draw {} unique identifiers from a keyspace of {} after {} retries",
+                    count, keyspace, UNIQUE_RETRY_BOUND
+                ));
+            }
+        }
+    }
+
+    Ok(ids)
 }
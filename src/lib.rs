@@ -1,11 +1,13 @@
 /*!
 Generate human readable identifier strings by chaning common (short) words of
-the english language.
+a natural language.
 
 This approach to the generation of identifiers is most commoly associated with
 the company [what3words](https://what3words.com/) who have generated
 fine-grained location identifiers that allow for very precise geo-location
-based on 3 common (native language) words.
+based on 3 common (native language) words. In that same spirit this crate
+ships word lists for more than English; call [`languages`] to enumerate
+the full set supported by the current version.
 
 # Example
 
@@ -14,7 +16,7 @@ allow progressively more customization of the generated identifier.
 
 ```
 use human_id::{
-    NO_SEPARATOR, Language, id, custom_id, custom_id_in_language
+    NO_SEPARATOR, Case, Language, id, custom_id, custom_id_in_language
 };
 
 id();                    // HungryDucksListen
@@ -24,63 +26,26 @@ custom_id("", true);     // ChattyWombatsCare
 
 custom_id_in_language(
     "-",
-    false,
+    Case::Lower,
     Language::En);       // tame-lions-retire
 custom_id_in_language(
     NO_SEPARATOR,
-    true,
+    Case::Title,
     Default::default()); // ChattyWombatsCare
 ```
 
 # Command-Line Tool
 
 This package also provides a command-line tool, `3wid` (for _three word
-identifier_) which can be used for simple identifier creation.
-
-```bash
-$ 3wid --help
-3wid 0.1.0
-Generate 3-word human identifiers.
-
-USAGE:
-    3wid [FLAGS] [OPTIONS]
-
-FLAGS:
-    -h, --help
-            Prints help information
-
-    -n, --no-capitalize
-            Turn off capitalization of words.
-
-            By default the three words comprising the identifier will have
-            their first character capitalized. This flag turns off this
-            feature and generates all lowercase identifiers.
-    -V, --version
-            Prints version information
-
-
-OPTIONS:
-    -c, --count <count>
-            The number of identifiers to generate, the default is one.
-
-            This is a useful option for creating batches of identifiers
-            with a common format. The generated identifiers are output
-            one per line.
-    -l, --language <language>
-            The language to choose words from, the default is 'en'.
-
-            The set of words can be chosen from any language although
-            the library only has a small set of chosen languages. The
-            string is the ISO 2-character language code in either all
-            lowercase `"en"` or all uppercase `"EN"` characters.
-    -s, --separator <separator>
-            The separator string to use between words in the identifier,
-            the default is none.
-
-            The separator appears between the 3 words, such that a
-            separator string `"/"` will create identifiers of the
-            form `Olive/Lamps/Offer`.
-```
+identifier_) which can be used for simple identifier creation. Besides the
+basic `-c`/`--count`, `-l`/`--language`, `-s`/`--separator` and
+`-n`/`--no-capitalize` options, it also supports `--case` for the full set
+of [`Case`] styles, `--seed` for reproducible output, `--template`/`--words`
+for identifiers built from a custom shape of word classes, `--format` for
+`lines`/`json`/`csv` output, `--unique` to de-duplicate a batch, `--entropy`
+to print the keyspace's bits of entropy instead of generating identifiers,
+and `--list-languages` to print the supported language codes. Run `3wid
+--help` for the authoritative, up-to-date list of flags and their defaults.
 
 The count (`-c` or `--count`) option allows the creation of batches of
 identifiers, in the following fashion.
@@ -117,15 +82,94 @@ use std::str::FromStr;
 ///
 pub const NO_SEPARATOR: &str = "";
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+///
+/// The case (and, for the joined styles, separator) to apply to the words
+/// of a generated identifier.
+///
+/// `CamelCase`, `PascalCase`, `SnakeCase` and `KebabCase` each imply their
+/// own separator, overriding whatever separator the caller passed in, in
+/// the same way [`convert_case`](https://docs.rs/convert_case)'s `Case`
+/// does.
+///
+pub enum Case {
+    /// All words lowercase, e.g. `tame lions retire`.
+    Lower,
+    /// Each word capitalized, e.g. `Tame Lions Retire`.
+    #[default]
+    Title,
+    /// All words uppercase, e.g. `TAME LIONS RETIRE`.
+    Upper,
+    /// Joined with no separator, first word lowercase, e.g. `tameLionsRetire`.
+    CamelCase,
+    /// Joined with no separator, every word capitalized, e.g. `TameLionsRetire`.
+    PascalCase,
+    /// Joined with `_`, all words lowercase, e.g. `tame_lions_retire`.
+    SnakeCase,
+    /// Joined with `-`, all words lowercase, e.g. `tame-lions-retire`.
+    KebabCase,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
 ///
 /// The language to select words from.
 ///
+/// Each variant owns its own word lists (see the `words` module) and knows
+/// its own English name and ISO 639-1 code, following the same one-module-
+/// per-language design used by elasticlunr's `Language` trait.
+///
 pub enum Language {
     /// English language words.
+    #[default]
     En,
+    /// German language words.
+    De,
+    /// Spanish language words.
+    Es,
+    /// French language words.
+    Fr,
+    /// Italian language words.
+    It,
 }
 
+/// All languages this crate currently ships word lists for, in the order
+/// they should be presented to a user (e.g. in `3wid --help`).
+pub const LANGUAGES: &[Language] = &[
+    Language::En,
+    Language::De,
+    Language::Es,
+    Language::Fr,
+    Language::It,
+];
+
+/// Returns the set of languages this crate currently ships word lists for.
+///
+/// ```
+/// use human_id::{languages, Language};
+///
+/// assert!(languages().contains(&Language::En));
+/// ```
+pub fn languages() -> &'static [Language] {
+    LANGUAGES
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+///
+/// The class of word to draw from when building an identifier from a
+/// template, see [`custom_id_from_template`].
+///
+pub enum WordClass {
+    /// An adjective, e.g. `tame`.
+    Adjective,
+    /// A noun, e.g. `lions`.
+    Noun,
+    /// A verb, e.g. `retire`.
+    Verb,
+}
+
+/// The default identifier shape: one adjective, one noun, one verb.
+pub const DEFAULT_TEMPLATE: &[WordClass] = &[WordClass::Adjective, WordClass::Noun, WordClass::Verb];
+
 // ------------------------------------------------------------------------------------------------
 // Public Functions
 // ------------------------------------------------------------------------------------------------
@@ -152,7 +196,9 @@ pub fn id() -> String {
 /// * the insertion of a sepatator string between words,
 /// * the choice of whether to capitalize the chosen words.
 ///
-/// The default language is used for word choices.
+/// The default language is used for word choices. `should_capitalize`
+/// maps to [`Case::Title`] when `true` and [`Case::Lower`] when `false`;
+/// use [`custom_id_in_language`] directly for the other [`Case`] styles.
 ///
 /// ```
 /// use human_id::custom_id;
@@ -165,7 +211,12 @@ pub fn custom_id<S>(separator: S, should_capitalize: bool) -> String
 where
     S: Into<String>,
 {
-    custom_id_in_language(separator, should_capitalize, Default::default())
+    let case = if should_capitalize {
+        Case::Title
+    } else {
+        Case::Lower
+    };
+    custom_id_in_language(separator, case, Default::default())
 }
 
 ///
@@ -173,68 +224,256 @@ where
 ///
 /// This form allows for full customization of the generated identifier:
 ///
-/// * the insertion of a sepatator string between words,
-/// * the choice of whether to capitalize the chosen words,
+/// * the insertion of a sepatator string between words, unless `case`
+///   implies its own separator,
+/// * the [`Case`] to apply to the chosen words,
 /// * the choice of language for word choices.
 ///
 /// ```
-/// use human_id::{NO_SEPARATOR, custom_id_in_language};
+/// use human_id::{custom_id_in_language, Case, Language, NO_SEPARATOR};
 ///
 /// custom_id_in_language(
 ///     "-",
-///     false,
-///     Language::En);         // tame-lions-retire
+///     Case::Lower,
+///     Language::En);              // tame-lions-retire
 /// custom_id_in_language(
 ///     NO_SEPARATOR,
-///     true,
-///     Default::default());   // ChattyWombatsCare
+///     Case::Title,
+///     Default::default());       // ChattyWombatsCare
 /// ```
 ///
-pub fn custom_id_in_language<S>(separator: S, should_capitalize: bool, language: Language) -> String
+pub fn custom_id_in_language<S>(separator: S, case: Case, language: Language) -> String
 where
     S: Into<String>,
 {
-    let mut rng = thread_rng();
+    custom_id_with_rng(&mut thread_rng(), separator, case, language)
+}
 
-    let may_capitalize = |x: &&str| {
-        if should_capitalize {
-            capitalize(*x)
-        } else {
-            x.to_string()
-        }
-    };
+///
+/// Generates a custom "human id" using the supplied random number
+/// generator, for reproducible identifiers.
+///
+/// The generation functions in this crate that take no RNG argument call
+/// [`thread_rng`] and so cannot be replayed; passing a seeded RNG here (e.g.
+/// `StdRng::seed_from_u64(seed)`) means the same seed always produces the
+/// same identifier, which is useful in tests, snapshots, or distributed
+/// pipelines that must regenerate an identical ID from the same seed.
+///
+/// ```
+/// use human_id::{custom_id_with_rng, Case, Language, NO_SEPARATOR};
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+///
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let a = custom_id_with_rng(&mut rng, NO_SEPARATOR, Case::Title, Language::En);
+///
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let b = custom_id_with_rng(&mut rng, NO_SEPARATOR, Case::Title, Language::En);
+///
+/// assert_eq!(a, b);
+/// ```
+///
+pub fn custom_id_with_rng<R, S>(rng: &mut R, separator: S, case: Case, language: Language) -> String
+where
+    R: rand::Rng,
+    S: Into<String>,
+{
+    custom_id_from_template_with_rng(rng, DEFAULT_TEMPLATE, separator, case, language)
+}
 
-    let chosen_words: (&[&str], &[&str], &[&str]) = match language {
-        Language::En => (&words::en::ADJECTIVES, &words::en::NOUNS, &words::en::VERBS),
-    };
+///
+/// Generates a custom "human id" from an explicit template of word classes.
+///
+/// The default identifier shape is exactly one adjective, one noun and one
+/// verb (see [`DEFAULT_TEMPLATE`]), but a template can ask for any sequence
+/// of [`WordClass`]es, e.g. `&[WordClass::Adjective, WordClass::Noun]` for
+/// a shorter two-word tag, or repeated classes for more entropy.
+///
+/// The resulting keyspace is the product of the pool sizes of the chosen
+/// classes (e.g. two adjectives and a noun in English gives `26 * 26 * 24`
+/// possible identifiers); a longer template, or one repeating a large pool
+/// like `Adjective`, grows that keyspace and so lowers the collision
+/// probability for a given batch size.
+///
+/// ```
+/// use human_id::{custom_id_from_template, Language, WordClass, NO_SEPARATOR};
+///
+/// let tag = custom_id_from_template(
+///     &[WordClass::Adjective, WordClass::Noun],
+///     "-",
+///     Default::default(),
+///     Language::En);  // Tame-Lions
+/// ```
+///
+pub fn custom_id_from_template<S>(
+    template: &[WordClass],
+    separator: S,
+    case: Case,
+    language: Language,
+) -> String
+where
+    S: Into<String>,
+{
+    custom_id_from_template_with_rng(&mut thread_rng(), template, separator, case, language)
+}
 
-    [
-        chosen_words
-            .0
-            .choose(&mut rng)
-            .map(may_capitalize)
-            .unwrap()
-            .to_string(),
-        chosen_words
-            .1
-            .choose(&mut rng)
-            .map(may_capitalize)
-            .unwrap()
-            .to_string(),
-        chosen_words
-            .2
-            .choose(&mut rng)
-            .map(may_capitalize)
-            .unwrap()
-            .to_string(),
-    ]
-    .join(&separator.into())
+///
+/// Generates a custom "human id" from an explicit template of word classes,
+/// using the supplied random number generator. See [`custom_id_from_template`]
+/// and [`custom_id_with_rng`].
+///
+pub fn custom_id_from_template_with_rng<R, S>(
+    rng: &mut R,
+    template: &[WordClass],
+    separator: S,
+    case: Case,
+    language: Language,
+) -> String
+where
+    R: rand::Rng,
+    S: Into<String>,
+{
+    let words = template
+        .iter()
+        .map(|class| language.words_for(*class).choose(rng).unwrap().to_string())
+        .collect();
+
+    apply_case(words, separator.into(), case)
+}
+
+/// The total keyspace size for `template` in `language`, i.e. the number
+/// of distinct identifiers it can produce: the product of the pool sizes
+/// of each [`WordClass`] in the template.
+///
+/// ```
+/// use human_id::{keyspace_size, Language, DEFAULT_TEMPLATE};
+///
+/// assert!(keyspace_size(DEFAULT_TEMPLATE, Language::En) > 0);
+/// ```
+pub fn keyspace_size(template: &[WordClass], language: Language) -> u64 {
+    template
+        .iter()
+        .map(|class| language.words_for(*class).len() as u64)
+        .product()
+}
+
+/// The entropy, in bits, of identifiers generated from `template` in
+/// `language`, i.e. `log2(keyspace_size(template, language))`. Useful for
+/// reasoning about collision probability when choosing a template.
+///
+/// ```
+/// use human_id::{entropy_bits, Language, DEFAULT_TEMPLATE};
+///
+/// assert!(entropy_bits(DEFAULT_TEMPLATE, Language::En) > 0.0);
+/// ```
+pub fn entropy_bits(template: &[WordClass], language: Language) -> f64 {
+    (keyspace_size(template, language) as f64).log2()
+}
+
+/// Encodes `n` as a human-readable identifier, the reverse of [`decode`].
+///
+/// The identifier space is treated as a mixed-radix number: with adjective,
+/// noun and verb pool sizes `A`, `N` and `V` (the word lists of `language`,
+/// kept in a fixed, sorted order), `n` is decomposed as
+///
+/// ```text
+/// adj_idx  = n / (N * V)
+/// noun_idx = (n % (N * V)) / V
+/// verb_idx = (n % (N * V)) % V
+/// ```
+///
+/// and the three words at those indices are joined with `separator`.
+/// Returns `None` if `n` is outside the `0..A*N*V` keyspace, or if
+/// `separator` is empty — an empty separator can't be split back apart by
+/// [`decode`], so [`NO_SEPARATOR`] is not a valid choice for this codec.
+///
+/// ```
+/// use human_id::{decode, encode, Language};
+///
+/// let id = encode(42, "-", Language::En).unwrap();
+/// assert_eq!(decode(&id, "-", Language::En), Some(42));
+/// ```
+///
+pub fn encode<S>(n: u64, separator: S, language: Language) -> Option<String>
+where
+    S: Into<String>,
+{
+    let separator = separator.into();
+    if separator.is_empty() {
+        return None;
+    }
+
+    let (adjectives, nouns, verbs) = language.word_lists();
+    let (a, nn, v) = (
+        adjectives.len() as u64,
+        nouns.len() as u64,
+        verbs.len() as u64,
+    );
+
+    if n >= a * nn * v {
+        return None;
+    }
+
+    let adj_idx = n / (nn * v);
+    let remainder = n % (nn * v);
+    let noun_idx = remainder / v;
+    let verb_idx = remainder % v;
+
+    Some(
+        [
+            adjectives[adj_idx as usize],
+            nouns[noun_idx as usize],
+            verbs[verb_idx as usize],
+        ]
+        .join(&separator),
+    )
+}
+
+/// Decodes an identifier produced by [`encode`] back into its integer value.
+///
+/// The identifier is split on `separator` and each of the three resulting
+/// words is matched back to its index in the `language` word lists,
+/// case-insensitively. Returns `None` if the identifier does not split into
+/// exactly three words, or if any word is not found in its word list.
+///
+/// ```
+/// use human_id::{decode, Language};
+///
+/// assert_eq!(decode("not-a-word", "-", Language::En), None);
+/// ```
+///
+pub fn decode<S>(id: &str, separator: S, language: Language) -> Option<u64>
+where
+    S: Into<String>,
+{
+    let separator = separator.into();
+    if separator.is_empty() {
+        return None;
+    }
+
+    let parts: Vec<&str> = id.split(separator.as_str()).collect();
+    if parts.len() != 3 {
+        return None;
+    }
+
+    let (adjectives, nouns, verbs) = language.word_lists();
+    let (nn, v) = (nouns.len() as u64, verbs.len() as u64);
+
+    let adj_idx = word_index(adjectives, parts[0])?;
+    let noun_idx = word_index(nouns, parts[1])?;
+    let verb_idx = word_index(verbs, parts[2])?;
+
+    Some(adj_idx as u64 * (nn * v) + noun_idx as u64 * v + verb_idx as u64)
 }
 
 // ------------------------------------------------------------------------------------------------
 // Private Functions
 // ------------------------------------------------------------------------------------------------
 
+fn word_index(words: &[&str], word: &str) -> Option<usize> {
+    words.iter().position(|w| w.eq_ignore_ascii_case(word))
+}
+
 fn capitalize<S>(input: S) -> String
 where
     S: Into<String>,
@@ -247,38 +486,180 @@ where
     }
 }
 
+/// Applies `case` to each of `words`, joining them with either `separator`
+/// or the separator `case` implies, if any.
+fn apply_case(words: Vec<String>, separator: String, case: Case) -> String {
+    let separator = case.separator_override().unwrap_or(&separator).to_string();
+
+    words
+        .into_iter()
+        .enumerate()
+        .map(|(index, word)| case.apply(&word, index))
+        .collect::<Vec<String>>()
+        .join(&separator)
+}
+
 // ------------------------------------------------------------------------------------------------
 // Implementations
 // ------------------------------------------------------------------------------------------------
 
-impl Default for Language {
-    fn default() -> Self {
-        Self::En
+impl Display for Language {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.code())
     }
 }
 
-impl Display for Language {
+impl FromStr for Language {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "en" => Ok(Self::En),
+            "de" => Ok(Self::De),
+            "es" => Ok(Self::Es),
+            "fr" => Ok(Self::Fr),
+            "it" => Ok(Self::It),
+            _ => Err(format!(
+                "The string '{}' is not a valid identifier, or supported language",
+                s
+            )),
+        }
+    }
+}
+
+impl Language {
+    /// The English name of this language, e.g. `"German"`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Language::En => "English",
+            Language::De => "German",
+            Language::Es => "Spanish",
+            Language::Fr => "French",
+            Language::It => "Italian",
+        }
+    }
+
+    /// The ISO 639-1 code for this language, e.g. `"de"`.
+    pub fn code(&self) -> &'static str {
+        match self {
+            Language::En => "en",
+            Language::De => "de",
+            Language::Es => "es",
+            Language::Fr => "fr",
+            Language::It => "it",
+        }
+    }
+
+    /// The `(adjectives, nouns, verbs)` word lists for this language.
+    fn word_lists(&self) -> (&'static [&'static str], &'static [&'static str], &'static [&'static str]) {
+        match self {
+            Language::En => (words::en::ADJECTIVES, words::en::NOUNS, words::en::VERBS),
+            Language::De => (words::de::ADJECTIVES, words::de::NOUNS, words::de::VERBS),
+            Language::Es => (words::es::ADJECTIVES, words::es::NOUNS, words::es::VERBS),
+            Language::Fr => (words::fr::ADJECTIVES, words::fr::NOUNS, words::fr::VERBS),
+            Language::It => (words::it::ADJECTIVES, words::it::NOUNS, words::it::VERBS),
+        }
+    }
+
+    /// The word list for a single [`WordClass`] in this language.
+    fn words_for(&self, class: WordClass) -> &'static [&'static str] {
+        let (adjectives, nouns, verbs) = self.word_lists();
+        match class {
+            WordClass::Adjective => adjectives,
+            WordClass::Noun => nouns,
+            WordClass::Verb => verbs,
+        }
+    }
+}
+
+impl Display for WordClass {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
             "{}",
             match self {
-                Language::En => "en",
+                WordClass::Adjective => "adjective",
+                WordClass::Noun => "noun",
+                WordClass::Verb => "verb",
             }
         )
     }
 }
 
-impl FromStr for Language {
+impl FromStr for WordClass {
     type Err = String;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s {
-            "en" | "EN" => Ok(Self::En),
-            _ => Err(format!(
-                "The string '{}' is not a valid identifier, or supported language",
-                s
-            )),
+        match s.to_lowercase().as_str() {
+            "adjective" | "adj" => Ok(Self::Adjective),
+            "noun" => Ok(Self::Noun),
+            "verb" => Ok(Self::Verb),
+            _ => Err(format!("The string '{}' is not a valid word class", s)),
+        }
+    }
+}
+
+impl Display for Case {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Case::Lower => "lower",
+                Case::Title => "title",
+                Case::Upper => "upper",
+                Case::CamelCase => "camel",
+                Case::PascalCase => "pascal",
+                Case::SnakeCase => "snake",
+                Case::KebabCase => "kebab",
+            }
+        )
+    }
+}
+
+impl FromStr for Case {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "lower" => Ok(Self::Lower),
+            "title" | "capitalized" => Ok(Self::Title),
+            "upper" => Ok(Self::Upper),
+            "camel" | "camelcase" => Ok(Self::CamelCase),
+            "pascal" | "pascalcase" => Ok(Self::PascalCase),
+            "snake" | "snakecase" => Ok(Self::SnakeCase),
+            "kebab" | "kebabcase" => Ok(Self::KebabCase),
+            _ => Err(format!("The string '{}' is not a valid case style", s)),
+        }
+    }
+}
+
+impl Case {
+    /// The separator this case style forces, overriding any separator the
+    /// caller asked for, or `None` if the caller's separator is honored.
+    fn separator_override(&self) -> Option<&'static str> {
+        match self {
+            Case::CamelCase | Case::PascalCase => Some(NO_SEPARATOR),
+            Case::SnakeCase => Some("_"),
+            Case::KebabCase => Some("-"),
+            Case::Lower | Case::Title | Case::Upper => None,
+        }
+    }
+
+    /// Applies this case style to the word at `position` within an
+    /// identifier (only `CamelCase` cares about the position).
+    fn apply(&self, word: &str, position: usize) -> String {
+        match self {
+            Case::Lower | Case::SnakeCase | Case::KebabCase => word.to_lowercase(),
+            Case::Title | Case::PascalCase => capitalize(word),
+            Case::Upper => word.to_uppercase(),
+            Case::CamelCase => {
+                if position == 0 {
+                    word.to_lowercase()
+                } else {
+                    capitalize(word)
+                }
+            }
         }
     }
 }
@@ -296,8 +677,13 @@ mod words;
 #[cfg(test)]
 mod tests {
 
-    use crate::custom_id;
     use crate::words::en::{ADJECTIVES, NOUNS, VERBS};
+    use crate::{
+        custom_id, custom_id_from_template, custom_id_in_language, custom_id_with_rng, decode,
+        encode, Case, Language, WordClass, NO_SEPARATOR,
+    };
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
 
     fn capitals<S>(s: S) -> usize
     where
@@ -327,4 +713,76 @@ mod tests {
         let capitals = capitals(&the_id);
         assert_eq!(capitals, 3);
     }
+
+    #[test]
+    fn encode_decode_round_trips_over_the_whole_keyspace() {
+        let keyspace = (ADJECTIVES.len() * NOUNS.len() * VERBS.len()) as u64;
+
+        for n in 0..keyspace {
+            let id = encode(n, "-", Language::En).unwrap();
+            assert_eq!(decode(&id, "-", Language::En), Some(n));
+        }
+    }
+
+    #[test]
+    fn encode_rejects_values_outside_the_keyspace() {
+        let keyspace = (ADJECTIVES.len() * NOUNS.len() * VERBS.len()) as u64;
+
+        assert_eq!(encode(keyspace, "-", Language::En), None);
+    }
+
+    #[test]
+    fn encode_and_decode_reject_no_separator() {
+        assert_eq!(encode(42, NO_SEPARATOR, Language::En), None);
+        assert_eq!(decode("braveboxesquestion", NO_SEPARATOR, Language::En), None);
+    }
+
+    #[test]
+    fn decode_rejects_unknown_words_and_wrong_word_count() {
+        assert_eq!(decode("tame-lions-xyzzy", "-", Language::En), None);
+        assert_eq!(decode("not-a-word-at-all", "-", Language::En), None);
+        assert_eq!(decode("tame-lions", "-", Language::En), None);
+        assert_eq!(decode("tame-lions-retire", "", Language::En), None);
+    }
+
+    #[test]
+    fn joined_cases_override_the_requested_separator() {
+        let snake = custom_id_in_language("-", Case::SnakeCase, Language::En);
+        assert!(snake.contains('_'));
+        assert!(!snake.contains('-'));
+
+        let kebab = custom_id_in_language("_", Case::KebabCase, Language::En);
+        assert!(kebab.contains('-'));
+        assert!(!kebab.contains('_'));
+
+        let camel = custom_id_in_language("-", Case::CamelCase, Language::En);
+        assert!(!camel.contains('-'));
+        assert!(camel.chars().next().unwrap().is_lowercase());
+    }
+
+    #[test]
+    fn same_seed_produces_the_same_identifier() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let a = custom_id_with_rng(&mut rng, "-", Case::Lower, Language::En);
+
+        let mut rng = StdRng::seed_from_u64(42);
+        let b = custom_id_with_rng(&mut rng, "-", Case::Lower, Language::En);
+
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn custom_id_from_template_honors_the_requested_shape() {
+        let tag = custom_id_from_template(
+            &[WordClass::Adjective, WordClass::Noun],
+            "-",
+            Case::Lower,
+            Language::En,
+        );
+
+        let parts = tag.split('-').collect::<Vec<&str>>();
+        assert_eq!(parts.len(), 2);
+        assert!(ADJECTIVES.contains(&parts[0]));
+        assert!(NOUNS.contains(&parts[1]));
+    }
 }
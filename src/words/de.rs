@@ -0,0 +1,18 @@
+//!
+//! Word lists for German (ISO 639-1 code `de`).
+//!
+
+///
+/// Adjectives used to build German identifiers.
+///
+pub const ADJECTIVES: &[&str] = &["freundlich", "froehlich", "hungrig", "klug", "leise", "lustig", "mutig", "neugierig", "ruhig", "sanft", "schnell", "stark", "stolz", "tapfer", "wild", "zart"];
+
+///
+/// Nouns used to build German identifiers.
+///
+pub const NOUNS: &[&str] = &["adler", "baeren", "enten", "fische", "fuechse", "hasen", "hunde", "igel", "katzen", "loewen", "maeuse", "pferde", "schafe", "tauben", "woelfe", "ziegen"];
+
+///
+/// Verbs used to build German identifiers.
+///
+pub const VERBS: &[&str] = &["finden", "fliegen", "lachen", "lehren", "lernen", "reisen", "rennen", "ruhen", "schwimmen", "singen", "spielen", "springen", "suchen", "tanzen", "traeumen", "warten"];
@@ -0,0 +1,18 @@
+//!
+//! Word lists for English (ISO 639-1 code `en`).
+//!
+
+///
+/// Adjectives used to build English identifiers.
+///
+pub const ADJECTIVES: &[&str] = &["brave", "chatty", "clever", "eager", "fuzzy", "gentle", "happy", "hungry", "icy", "jolly", "jumpy", "khaki", "kind", "lively", "merry", "noisy", "orange", "plucky", "proud", "quiet", "rusty", "silly", "tame", "tangy", "tidy", "witty"];
+
+///
+/// Nouns used to build English identifiers.
+///
+pub const NOUNS: &[&str] = &["aliens", "boxes", "cats", "dogs", "ducks", "eagles", "flies", "foxes", "friends", "goats", "hares", "ibis", "jaguars", "koalas", "lions", "llamas", "moles", "newts", "otters", "pandas", "quails", "rabbits", "turkeys", "wombats"];
+
+///
+/// Verbs used to build English identifiers.
+///
+pub const VERBS: &[&str] = &["care", "dance", "dream", "explore", "float", "giggle", "hide", "hum", "imagine", "jump", "kneel", "laugh", "listen", "mingle", "nap", "observe", "occur", "ponder", "question", "relate", "repair", "rest", "retire", "sing"];
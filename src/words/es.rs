@@ -0,0 +1,18 @@
+//!
+//! Word lists for Spanish (ISO 639-1 code `es`).
+//!
+
+///
+/// Adjectives used to build Spanish identifiers.
+///
+pub const ADJECTIVES: &[&str] = &["alegre", "amable", "astuto", "audaz", "callado", "curioso", "fuerte", "hambriento", "orgulloso", "rapido", "sabio", "salvaje", "suave", "tierno", "tranquilo", "valiente"];
+
+///
+/// Nouns used to build Spanish identifiers.
+///
+pub const NOUNS: &[&str] = &["aguilas", "caballos", "cabras", "conejos", "erizos", "gatos", "leones", "lobos", "osos", "ovejas", "palomas", "patos", "peces", "perros", "ratones", "zorros"];
+
+///
+/// Verbs used to build Spanish identifiers.
+///
+pub const VERBS: &[&str] = &["aprender", "bailar", "buscar", "cantar", "correr", "descansar", "encontrar", "ensenar", "esperar", "jugar", "nadar", "reir", "saltar", "sonar", "viajar", "volar"];
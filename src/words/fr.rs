@@ -0,0 +1,18 @@
+//!
+//! Word lists for French (ISO 639-1 code `fr`).
+//!
+
+///
+/// Adjectives used to build French identifiers.
+///
+pub const ADJECTIVES: &[&str] = &["affame", "audacieux", "calme", "courageux", "curieux", "doux", "fier", "fort", "gentil", "joyeux", "rapide", "ruse", "sage", "sauvage", "silencieux", "tendre"];
+
+///
+/// Nouns used to build French identifiers.
+///
+pub const NOUNS: &[&str] = &["aigles", "canards", "chats", "chevaux", "chevres", "chiens", "herissons", "lapins", "lions", "loups", "moutons", "ours", "pigeons", "poissons", "renards", "souris"];
+
+///
+/// Verbs used to build French identifiers.
+///
+pub const VERBS: &[&str] = &["apprendre", "attendre", "chanter", "chercher", "courir", "danser", "enseigner", "jouer", "nager", "reposer", "rever", "rire", "sauter", "trouver", "voler", "voyager"];
@@ -0,0 +1,18 @@
+//!
+//! Word lists for Italian (ISO 639-1 code `it`).
+//!
+
+///
+/// Adjectives used to build Italian identifiers.
+///
+pub const ADJECTIVES: &[&str] = &["affamato", "allegro", "astuto", "audace", "calmo", "coraggioso", "curioso", "dolce", "fiero", "forte", "gentile", "saggio", "selvaggio", "silenzioso", "tenero", "veloce"];
+
+///
+/// Nouns used to build Italian identifiers.
+///
+pub const NOUNS: &[&str] = &["anatre", "aquile", "cani", "capre", "cavalli", "conigli", "gatti", "leoni", "lupi", "orsi", "pecore", "pesci", "piccioni", "ricci", "topi", "volpi"];
+
+///
+/// Verbs used to build Italian identifiers.
+///
+pub const VERBS: &[&str] = &["aspettare", "ballare", "cantare", "cercare", "correre", "giocare", "imparare", "insegnare", "nuotare", "ridere", "riposare", "saltare", "sognare", "trovare", "viaggiare", "volare"];
@@ -0,0 +1,19 @@
+//!
+//! Word lists for each supported [`crate::Language`].
+//!
+//! Each language module exposes three sorted, fixed-order word lists
+//! (`ADJECTIVES`, `NOUNS`, `VERBS`) that the generator and the codec both
+//! draw from. Keeping the lists in a stable, sorted order means an index
+//! into a list always refers to the same word, which the encode/decode
+//! functions in the top-level module rely on.
+//!
+
+pub mod en;
+
+pub mod de;
+
+pub mod es;
+
+pub mod fr;
+
+pub mod it;